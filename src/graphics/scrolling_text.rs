@@ -20,18 +20,99 @@
 //!
 //! See examples/scroll_text.rs for a complete example.
 
-use tiny_led_matrix::Render;
+use tiny_led_matrix::{Render, MAX_BRIGHTNESS};
 
 use crate::graphics::font;
 use crate::graphics::image::BitImage;
 use crate::graphics::scrolling::{Animate, ScrollingState, Scrollable};
 
+/// Number of sub-column steps between one LED column and the next, used by
+/// the smooth-scrolling mode.
+const SUB_COLUMNS: u8 = 8;
+
+/// Width, in columns, of one character's bitmap (matching the width of the
+/// microbit's LED matrix).
+const GLYPH_WIDTH: usize = 5;
+
+/// Clamps a scroll/row speed to the valid `1..=SUB_COLUMNS` range.
+fn clamp_scroll_step(step: u8) -> u8 {
+    step.clamp(1, SUB_COLUMNS)
+}
+
+/// Total number of columns a `length`-character message scrolls through,
+/// including the final run-off needed for the last character to fully exit
+/// the display.
+fn total_columns_from(length: usize) -> usize {
+    length * GLYPH_WIDTH + GLYPH_WIDTH
+}
+
+/// Brightness at `(x, y)` for a single (non-fractional) scroll column,
+/// scaled to `brightness`.
+fn single_brightness_at<T: Animate>(scroller: &T, x: usize, y: usize, brightness: u8) -> u8 {
+    if scroller.current_brightness_at(x, y) == 0 {
+        0
+    } else {
+        brightness.min(MAX_BRIGHTNESS)
+    }
+}
+
+/// Brightness at `(x, y)`, blending the columns either side of the current
+/// sub-column `fraction` (out of [`SUB_COLUMNS`]).
+fn scaled_brightness_at<T: Animate>(
+    scroller: &T,
+    x: usize,
+    y: usize,
+    fraction: u8,
+    brightness: u8,
+) -> u8 {
+    let left = single_brightness_at(scroller, x, y, brightness);
+    if fraction == 0 {
+        return left;
+    }
+    let right = single_brightness_at(scroller, x + 1, y, brightness);
+    let f = fraction as u16;
+    let level = (left as u16 * (SUB_COLUMNS - fraction) as u16 + right as u16 * f)
+        / SUB_COLUMNS as u16;
+    level.min(MAX_BRIGHTNESS as u16) as u8
+}
+
+/// `(current_column, total_columns)`, clamping `position` to `length`.
+fn progress_from(position: usize, length: usize) -> (usize, usize) {
+    (position.min(length), length)
+}
+
+/// `progress_from`, as a fraction of `u8::MAX`.
+fn progress_fraction_from(position: usize, length: usize) -> u8 {
+    match (position.min(length) * u8::MAX as usize).checked_div(length) {
+        Some(fraction) => fraction as u8,
+        None => u8::MAX,
+    }
+}
+
 /// A [`Scrollable`] displaying a static ascii byte-string slice.
-#[derive(Default)]
 #[derive(Copy, Clone)]
 pub struct ScrollingStaticText {
     message: &'static [u8],
+    position: usize,
     state: ScrollingState,
+    brightness: u8,
+    scroll_step: u8,
+    scroll_fraction: u8,
+}
+
+impl Default for ScrollingStaticText {
+
+    fn default() -> ScrollingStaticText {
+        ScrollingStaticText {
+            message: &[],
+            position: 0,
+            state: Default::default(),
+            brightness: MAX_BRIGHTNESS,
+            scroll_step: SUB_COLUMNS,
+            scroll_fraction: 0,
+        }
+    }
+
 }
 
 impl ScrollingStaticText {
@@ -44,6 +125,71 @@ impl ScrollingStaticText {
         self.reset();
     }
 
+    /// Sets the brightness used to display the message.
+    ///
+    /// `level` is clamped to [`MAX_BRIGHTNESS`]. This doesn't affect the
+    /// animation; it can be called at any time, including mid-scroll.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.brightness = level;
+    }
+
+    /// Sets how many sub-column steps `tick()` advances by, out of
+    /// [`SUB_COLUMNS`] per LED column.
+    ///
+    /// Clamped to `1..=SUB_COLUMNS`. The default, `SUB_COLUMNS`, advances a
+    /// whole column on every tick (the original behaviour). A smaller step
+    /// makes `tick()` advance the scroll position smoothly, in fractions of
+    /// a column, giving an anti-aliased scroll at the cost of needing more
+    /// ticks to cross the display.
+    pub fn set_scroll_speed(&mut self, step: u8) {
+        self.scroll_step = clamp_scroll_step(step);
+    }
+
+    /// Resets the animation to the beginning.
+    pub fn reset(&mut self) {
+        self.scroll_fraction = 0;
+        self.position = 0;
+        <Self as Scrollable>::reset(self);
+    }
+
+    /// Advances the animation by one tick.
+    ///
+    /// In smooth-scrolling mode (see [`set_scroll_speed`](Self::set_scroll_speed))
+    /// this advances the sub-column fraction; the display only scrolls by a
+    /// whole LED column once the fraction wraps round.
+    pub fn tick(&mut self) {
+        self.scroll_fraction += self.scroll_step;
+        while self.scroll_fraction >= SUB_COLUMNS {
+            self.scroll_fraction -= SUB_COLUMNS;
+            <Self as Scrollable>::tick(self);
+            self.position += 1;
+        }
+    }
+
+    /// Returns `(current_column, total_columns)`, reflecting how far the
+    /// animation has scrolled.
+    pub fn progress(&self) -> (usize, usize) {
+        progress_from(self.position, self.total_columns())
+    }
+
+    /// Returns the current scroll progress as a fraction of [`u8::MAX`].
+    pub fn progress_fraction(&self) -> u8 {
+        progress_fraction_from(self.position, self.total_columns())
+    }
+
+    /// Returns true once the animation has finished, including any
+    /// in-progress fractional step.
+    pub fn is_finished(&self) -> bool {
+        self.scroll_fraction == 0 && <Self as Scrollable>::is_finished(self)
+    }
+
+    /// Total number of columns the animation scrolls through, including the
+    /// final run-off needed for the last character to fully exit the
+    /// display.
+    fn total_columns(&self) -> usize {
+        total_columns_from(self.length())
+    }
+
 }
 
 impl Scrollable for ScrollingStaticText {
@@ -72,38 +218,227 @@ impl Scrollable for ScrollingStaticText {
 impl Render for ScrollingStaticText {
 
     fn brightness_at(&self, x: usize, y: usize) -> u8 {
-        self.current_brightness_at(x, y)
+        scaled_brightness_at(self, x, y, self.scroll_fraction, self.brightness)
     }
 
 }
 
 
+/// The escape byte introducing an in-band control code, as recognised by
+/// [`ScrollingBufferedText::set_message`].
+const CONTROL_ESCAPE: u8 = 0x1b;
+
+/// A built-in glyph substituted by the `i`*N* control code.
+const ICONS: [BitImage; 2] = [
+    // Heart
+    BitImage::new([
+        0b01010,
+        0b11111,
+        0b11111,
+        0b01110,
+        0b00100,
+    ]),
+    // Smiley
+    BitImage::new([
+        0b01010,
+        0b01010,
+        0b00000,
+        0b10001,
+        0b01110,
+    ]),
+];
+
+/// Effects applied as the scroll position reaches a given character cell,
+/// as set up by in-band control codes (see
+/// [`ScrollingBufferedText::set_message`]).
+///
+/// Several codes can target the same cell (e.g. a brightness change right
+/// before an icon): each field is independent, so setting one never
+/// discards another already pending for that cell.
+#[derive(Copy, Clone, Default)]
+struct ScrollEvent {
+    /// Set the brightness to the given level (`b`*N*).
+    brightness: Option<u8>,
+    /// Set the scroll speed to the given step (`s`*N*).
+    speed: Option<u8>,
+    /// Display the given built-in icon instead of a font glyph (`i`*N*).
+    icon: Option<usize>,
+}
+
 /// A [`Scrollable`] displaying an ascii byte-string of up to 128 bytes.
+///
+/// The message may contain in-band control codes: a `0x1b` byte followed by
+/// a command letter and a single decimal digit. `b`*N* sets the brightness
+/// (0..=9), `s`*N* sets the scroll speed (see
+/// [`set_scroll_speed`](Self::set_scroll_speed)), `p`*N* inserts *N* blank
+/// pause cells, and `i`*N* substitutes a built-in icon (see [`ICONS`]) for
+/// the next character cell. Control codes are consumed while the message is
+/// set and never occupy a visible cell themselves (a `p`*N* pause is *N*
+/// whole blank character cells, not individual columns); `b` and `s` take
+/// effect as the scroll position reaches the following cell. Codes may be
+/// combined onto the same cell (e.g. a brightness change immediately
+/// before an icon).
 #[derive(Copy, Clone)]
 pub struct ScrollingBufferedText {
     length: usize,
     message: [u8; 128],
+    events: [ScrollEvent; 128],
+    position: usize,
     state: ScrollingState,
+    brightness: u8,
+    scroll_step: u8,
+    scroll_fraction: u8,
 }
 
 impl ScrollingBufferedText {
 
     /// Specifies the ascii byte-string to be displayed.
     ///
-    /// Makes a copy of the byte-string.
+    /// Makes a copy of the byte-string, interpreting any in-band control
+    /// codes (see the type-level documentation).
     ///
     /// This also resets the animation to the beginning.
     ///
     /// # Panics
     ///
-    /// Panics if `message` is more than 128 bytes long.
+    /// Panics if a real or icon cell doesn't fit in the 128-cell capacity,
+    /// once control codes are removed; blank cells inserted by an earlier
+    /// `p`*N* pause count towards that capacity. A `p`*N* pause never
+    /// panics: it's silently truncated to however many blank cells still
+    /// fit in the remaining 128-cell capacity.
     pub fn set_message(&mut self, message: &[u8]) {
-        assert!(message.len() <= 128, "message too long");
-        self.length = message.len();
-        self.message[..self.length].copy_from_slice(message);
+        self.length = 0;
+        self.events = [ScrollEvent::default(); 128];
+        let mut pending = ScrollEvent::default();
+        let mut i = 0;
+        while i < message.len() {
+            let byte = message[i];
+            if byte == CONTROL_ESCAPE && i + 2 < message.len() {
+                let command = message[i + 1];
+                let digit = message[i + 2];
+                if digit.is_ascii_digit() {
+                    let n = digit - b'0';
+                    match command {
+                        b'b' => pending.brightness = Some(n),
+                        b's' => pending.speed = Some(n),
+                        b'i' => {
+                            pending.icon = Some(n as usize % ICONS.len());
+                            self.push_cell(b' ', core::mem::take(&mut pending));
+                        }
+                        b'p' => {
+                            let count = (n as usize).min(128 - self.length);
+                            for _ in 0..count {
+                                self.push_cell(b' ', core::mem::take(&mut pending));
+                            }
+                        }
+                        _ => {}
+                    }
+                    i += 3;
+                    continue;
+                }
+            }
+            self.push_cell(byte, core::mem::take(&mut pending));
+            i += 1;
+        }
         self.reset();
     }
 
+    /// Appends one character cell to the message buffer, recording any
+    /// pending control event against it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is already full.
+    fn push_cell(&mut self, byte: u8, event: ScrollEvent) {
+        assert!(self.length < 128, "message too long");
+        self.message[self.length] = byte;
+        self.events[self.length] = event;
+        self.length += 1;
+    }
+
+    /// Sets the brightness used to display the message.
+    ///
+    /// `level` is clamped to [`MAX_BRIGHTNESS`]. This doesn't affect the
+    /// animation; it can be called at any time, including mid-scroll.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.brightness = level;
+    }
+
+    /// Sets how many sub-column steps `tick()` advances by, out of
+    /// [`SUB_COLUMNS`] per LED column.
+    ///
+    /// Clamped to `1..=SUB_COLUMNS`. The default, `SUB_COLUMNS`, advances a
+    /// whole column on every tick (the original behaviour). A smaller step
+    /// makes `tick()` advance the scroll position smoothly, in fractions of
+    /// a column, giving an anti-aliased scroll at the cost of needing more
+    /// ticks to cross the display.
+    pub fn set_scroll_speed(&mut self, step: u8) {
+        self.scroll_step = clamp_scroll_step(step);
+    }
+
+    /// Resets the animation to the beginning.
+    pub fn reset(&mut self) {
+        self.scroll_fraction = 0;
+        self.position = 0;
+        <Self as Scrollable>::reset(self);
+        self.apply_event_at(self.position);
+    }
+
+    /// Advances the animation by one tick.
+    ///
+    /// In smooth-scrolling mode (see [`set_scroll_speed`](Self::set_scroll_speed))
+    /// this advances the sub-column fraction; the display only scrolls by a
+    /// whole LED column once the fraction wraps round. Any `b` or `s`
+    /// control code reaching the current cell takes effect at that point.
+    pub fn tick(&mut self) {
+        self.scroll_fraction += self.scroll_step;
+        while self.scroll_fraction >= SUB_COLUMNS {
+            self.scroll_fraction -= SUB_COLUMNS;
+            <Self as Scrollable>::tick(self);
+            self.position += 1;
+            if self.position % GLYPH_WIDTH == 0 {
+                self.apply_event_at(self.position / GLYPH_WIDTH);
+            }
+        }
+    }
+
+    /// Applies the control event recorded at `index`, if any.
+    fn apply_event_at(&mut self, index: usize) {
+        if index >= self.length {
+            return;
+        }
+        let event = self.events[index];
+        if let Some(n) = event.brightness {
+            self.brightness = n;
+        }
+        if let Some(n) = event.speed {
+            self.scroll_step = clamp_scroll_step(n);
+        }
+    }
+
+    /// Returns true once the animation has finished, including any
+    /// in-progress fractional step.
+    pub fn is_finished(&self) -> bool {
+        self.scroll_fraction == 0 && <Self as Scrollable>::is_finished(self)
+    }
+
+    /// Returns `(current_column, total_columns)`, reflecting how far the
+    /// animation has scrolled.
+    pub fn progress(&self) -> (usize, usize) {
+        progress_from(self.position, self.total_columns())
+    }
+
+    /// Returns the current scroll progress as a fraction of [`u8::MAX`].
+    pub fn progress_fraction(&self) -> u8 {
+        progress_fraction_from(self.position, self.total_columns())
+    }
+
+    /// Total number of columns the animation scrolls through, including the
+    /// final run-off needed for the last character to fully exit the
+    /// display.
+    fn total_columns(&self) -> usize {
+        total_columns_from(self.length())
+    }
 
 }
 
@@ -113,7 +448,12 @@ impl Default for ScrollingBufferedText {
         ScrollingBufferedText {
             length: 0,
             message: [0; 128],
+            events: [ScrollEvent::default(); 128],
+            position: 0,
             state: Default::default(),
+            brightness: MAX_BRIGHTNESS,
+            scroll_step: SUB_COLUMNS,
+            scroll_fraction: 0,
         }
     }
 
@@ -136,7 +476,11 @@ impl Scrollable for ScrollingBufferedText {
     }
 
     fn subimage(&self, index: usize) -> &BitImage {
-        font::character(self.message[index])
+        if let Some(n) = self.events[index].icon {
+            &ICONS[n]
+        } else {
+            font::character(self.message[index])
+        }
     }
 
 }
@@ -144,8 +488,550 @@ impl Scrollable for ScrollingBufferedText {
 impl Render for ScrollingBufferedText {
 
     fn brightness_at(&self, x: usize, y: usize) -> u8 {
-        self.current_brightness_at(x, y)
+        scaled_brightness_at(self, x, y, self.scroll_fraction, self.brightness)
+    }
+
+}
+
+
+/// Capacity, in bytes, of a [`ScrollingStreamText`]'s ring buffer.
+const STREAM_CAPACITY: usize = 128;
+
+/// A [`Scrollable`] displaying a live stream of ascii bytes.
+///
+/// Unlike [`ScrollingBufferedText`], the message isn't set all at once:
+/// bytes are appended with [`push`](Self::push) or
+/// [`push_str`](Self::push_str) as they become available, and the oldest
+/// bytes are dropped once the internal buffer (holding up to
+/// [`STREAM_CAPACITY`] bytes) is full. The display behaves like a
+/// never-ending scrolling console: [`is_finished`](Self::is_finished)
+/// always reports `false`, so a display loop driven by it keeps scrolling
+/// indefinitely as new text arrives.
+#[derive(Copy, Clone)]
+pub struct ScrollingStreamText {
+    buffer: [u8; STREAM_CAPACITY],
+    tail: usize,
+    length: usize,
+    position: usize,
+    state: ScrollingState,
+    brightness: u8,
+}
+
+impl Default for ScrollingStreamText {
+
+    fn default() -> ScrollingStreamText {
+        ScrollingStreamText {
+            buffer: [0; STREAM_CAPACITY],
+            tail: 0,
+            length: 0,
+            position: 0,
+            state: Default::default(),
+            brightness: MAX_BRIGHTNESS,
+        }
+    }
+
+}
+
+impl ScrollingStreamText {
+
+    /// Appends one ascii byte to the stream.
+    ///
+    /// If the buffer is already full, the oldest byte is dropped to make
+    /// room. Dropping a byte re-bases every remaining index by one
+    /// character, so the scroll position is pulled back by one glyph's
+    /// width to compensate: without this, whatever was on screen would
+    /// jump forward by a whole character, independently of any `tick()`.
+    pub fn push(&mut self, byte: u8) {
+        let head = (self.tail + self.length) % STREAM_CAPACITY;
+        self.buffer[head] = byte;
+        if self.length < STREAM_CAPACITY {
+            self.length += 1;
+        } else {
+            self.tail = (self.tail + 1) % STREAM_CAPACITY;
+            self.position = self.position.saturating_sub(GLYPH_WIDTH);
+        }
+    }
+
+    /// Appends a slice of ascii bytes to the stream.
+    pub fn push_str(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.push(byte);
+        }
+    }
+
+    /// Sets the brightness used to display the stream.
+    ///
+    /// `level` is clamped to [`MAX_BRIGHTNESS`]. This doesn't affect the
+    /// animation; it can be called at any time, including mid-scroll.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.brightness = level;
+    }
+
+    /// Returns `false`: a [`ScrollingStreamText`] scrolls indefinitely, so
+    /// it's never finished.
+    pub fn is_finished(&self) -> bool {
+        false
+    }
+
+    /// Advances the animation by one tick.
+    pub fn tick(&mut self) {
+        <Self as Scrollable>::tick(self);
+        self.position = self.position.saturating_add(1);
     }
 
+    /// Returns `(current_column, total_columns)`, reflecting how far the
+    /// animation has scrolled through the bytes currently buffered.
+    ///
+    /// Since new bytes keep arriving, `total_columns` (and so
+    /// `current_column`) only reflects the buffer's occupancy at the time
+    /// of the call.
+    pub fn progress(&self) -> (usize, usize) {
+        progress_from(self.position, self.total_columns())
+    }
+
+    /// Returns the current scroll progress as a fraction of [`u8::MAX`].
+    pub fn progress_fraction(&self) -> u8 {
+        progress_fraction_from(self.position, self.total_columns())
+    }
+
+    /// Total number of columns the animation scrolls through, including the
+    /// final run-off needed for the last buffered character to fully exit
+    /// the display.
+    fn total_columns(&self) -> usize {
+        total_columns_from(self.length())
+    }
+
+}
+
+impl Scrollable for ScrollingStreamText {
+
+    type Subimage = BitImage;
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn state(&self) -> &ScrollingState {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut ScrollingState {
+        &mut self.state
+    }
+
+    fn subimage(&self, index: usize) -> &BitImage {
+        font::character(self.buffer[(self.tail + index) % STREAM_CAPACITY])
+    }
+
+}
+
+impl Render for ScrollingStreamText {
+
+    // This computes the displayed glyph directly from `self.position`
+    // rather than going through `Animate::current_brightness_at`: that
+    // machinery indexes `subimage()` off the scroll state's own position,
+    // which isn't re-based when `push()` rotates `tail`, so it would skip
+    // a character on every eviction (see `push`'s doc comment).
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        let virtual_column = self.position + x;
+        let char_index = virtual_column / GLYPH_WIDTH;
+        if char_index >= self.length() {
+            return 0;
+        }
+        let column_in_char = virtual_column % GLYPH_WIDTH;
+        if self.subimage(char_index).brightness_at(column_in_char, y) == 0 {
+            0
+        } else {
+            self.brightness.min(MAX_BRIGHTNESS)
+        }
+    }
+
+}
+
+
+/// Height, in rows, of one character's bitmap (matching the height of the
+/// microbit's LED matrix).
+const GLYPH_HEIGHT: usize = 5;
+
+/// A [`Scrollable`] displaying an ascii byte-string, scrolling upward one
+/// row (or, in smooth mode, one fractional row) per tick.
+///
+/// Unlike the horizontal scrollers, characters are stacked vertically
+/// rather than laid out side by side, separated by a configurable
+/// blank-row gap (see [`set_gap`](Self::set_gap)). This suits a square LED
+/// grid better than horizontal scrolling when displaying short,
+/// multi-line notifications.
+#[derive(Copy, Clone)]
+pub struct ScrollingVerticalTextUp {
+    length: usize,
+    message: [u8; 128],
+    state: ScrollingState,
+    gap: usize,
+    brightness: u8,
+    row_step: u8,
+    row_fraction: u8,
+    row_position: usize,
+}
+
+impl Default for ScrollingVerticalTextUp {
+
+    fn default() -> ScrollingVerticalTextUp {
+        ScrollingVerticalTextUp {
+            length: 0,
+            message: [0; 128],
+            state: Default::default(),
+            gap: 1,
+            brightness: MAX_BRIGHTNESS,
+            row_step: SUB_COLUMNS,
+            row_fraction: 0,
+            row_position: 0,
+        }
+    }
+
+}
+
+impl ScrollingVerticalTextUp {
+
+    /// Specifies the ascii byte-string to be displayed.
+    ///
+    /// Makes a copy of the byte-string.
+    ///
+    /// This also resets the animation to the beginning.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message` is more than 128 bytes long.
+    pub fn set_message(&mut self, message: &[u8]) {
+        assert!(message.len() <= 128, "message too long");
+        self.length = message.len();
+        self.message[..self.length].copy_from_slice(message);
+        self.reset();
+    }
+
+    /// Sets the number of blank rows separating successive characters.
+    pub fn set_gap(&mut self, gap: usize) {
+        self.gap = gap;
+    }
+
+    /// Sets the brightness used to display the message.
+    ///
+    /// `level` is clamped to [`MAX_BRIGHTNESS`]. This doesn't affect the
+    /// animation; it can be called at any time, including mid-scroll.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.brightness = level;
+    }
+
+    /// Sets how many sub-row steps `tick()` advances by, out of
+    /// [`SUB_COLUMNS`] per LED row.
+    ///
+    /// Clamped to `1..=SUB_COLUMNS`. The default, `SUB_COLUMNS`, advances a
+    /// whole row on every tick (the same pace as the horizontal scrollers).
+    /// A smaller step makes `tick()` advance the scroll position smoothly,
+    /// in fractions of a row.
+    pub fn set_scroll_speed(&mut self, step: u8) {
+        self.row_step = clamp_scroll_step(step);
+    }
+
+    /// Resets the animation to the beginning.
+    pub fn reset(&mut self) {
+        self.row_position = 0;
+        self.row_fraction = 0;
+        <Self as Scrollable>::reset(self);
+    }
+
+    /// Advances the animation by one tick.
+    ///
+    /// In smooth-scrolling mode (see [`set_scroll_speed`](Self::set_scroll_speed))
+    /// this advances the sub-row fraction; the display only scrolls by a
+    /// whole LED row once the fraction wraps round.
+    pub fn tick(&mut self) {
+        self.row_fraction += self.row_step;
+        while self.row_fraction >= SUB_COLUMNS {
+            self.row_fraction -= SUB_COLUMNS;
+            self.row_position += 1;
+            <Self as Scrollable>::tick(self);
+        }
+    }
+
+    /// Returns true once the last character has fully scrolled off the top
+    /// of the display.
+    pub fn is_finished(&self) -> bool {
+        self.row_fraction == 0 && self.row_position >= self.total_rows()
+    }
+
+    /// Returns `(current_row, total_rows)`, reflecting how far the
+    /// animation has scrolled.
+    pub fn progress(&self) -> (usize, usize) {
+        progress_from(self.row_position, self.total_rows())
+    }
+
+    /// Returns the current scroll progress as a fraction of [`u8::MAX`].
+    pub fn progress_fraction(&self) -> u8 {
+        progress_fraction_from(self.row_position, self.total_rows())
+    }
+
+    /// Total number of rows the animation scrolls through: the stacked
+    /// message starts on the display, so this is the number of rows after
+    /// which the last character has fully exited the top of the display.
+    fn total_rows(&self) -> usize {
+        (self.length * (GLYPH_HEIGHT + self.gap)).saturating_sub(self.gap)
+    }
+
+    /// Brightness at column `x` of virtual row `virtual_row` (a row index
+    /// into the stacked, gap-separated message), scaled to the configured
+    /// brightness.
+    fn row_brightness_at(&self, x: usize, virtual_row: usize) -> u8 {
+        let period = GLYPH_HEIGHT + self.gap;
+        let char_index = virtual_row / period;
+        let row_in_char = virtual_row % period;
+        if char_index >= self.length || row_in_char >= GLYPH_HEIGHT {
+            return 0;
+        }
+        if self.subimage(char_index).brightness_at(x, row_in_char) == 0 {
+            0
+        } else {
+            self.brightness.min(MAX_BRIGHTNESS)
+        }
+    }
+
+}
+
+impl Scrollable for ScrollingVerticalTextUp {
+
+    type Subimage = BitImage;
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn state(&self) -> &ScrollingState {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut ScrollingState {
+        &mut self.state
+    }
+
+    fn subimage(&self, index: usize) -> &BitImage {
+        font::character(self.message[index])
+    }
+
+}
+
+impl Render for ScrollingVerticalTextUp {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        let virtual_row = self.row_position + y;
+        let top = self.row_brightness_at(x, virtual_row);
+        if self.row_fraction == 0 {
+            return top;
+        }
+        let bottom = self.row_brightness_at(x, virtual_row + 1);
+        let f = self.row_fraction as u16;
+        let level = (top as u16 * (SUB_COLUMNS - self.row_fraction) as u16 + bottom as u16 * f)
+            / SUB_COLUMNS as u16;
+        level.min(MAX_BRIGHTNESS as u16) as u8
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_from_clamps_to_length() {
+        assert_eq!(progress_from(0, 10), (0, 10));
+        assert_eq!(progress_from(4, 10), (4, 10));
+        assert_eq!(progress_from(15, 10), (10, 10));
+    }
+
+    #[test]
+    fn progress_fraction_from_scales_to_u8_max() {
+        assert_eq!(progress_fraction_from(0, 10), 0);
+        assert_eq!(progress_fraction_from(10, 10), u8::MAX);
+        assert_eq!(progress_fraction_from(5, 10), u8::MAX / 2);
+    }
+
+    #[test]
+    fn progress_fraction_from_handles_zero_length() {
+        assert_eq!(progress_fraction_from(0, 0), u8::MAX);
+    }
+
+    /// A minimal [`Scrollable`] with a single, caller-chosen glyph, used to
+    /// exercise [`scaled_brightness_at`] without depending on the real font.
+    struct TestScroller {
+        state: ScrollingState,
+        glyph: BitImage,
+    }
+
+    impl Scrollable for TestScroller {
+        type Subimage = BitImage;
+
+        fn length(&self) -> usize {
+            1
+        }
+
+        fn state(&self) -> &ScrollingState {
+            &self.state
+        }
+
+        fn state_mut(&mut self) -> &mut ScrollingState {
+            &mut self.state
+        }
+
+        fn subimage(&self, _index: usize) -> &BitImage {
+            &self.glyph
+        }
+    }
+
+    #[test]
+    fn scaled_brightness_at_is_unblended_at_zero_fraction() {
+        let mut scroller = TestScroller {
+            state: Default::default(),
+            glyph: BitImage::new([0b10000, 0, 0, 0, 0]),
+        };
+        // The animation starts blank; scroll the glyph fully onto the display.
+        for _ in 0..GLYPH_WIDTH {
+            scroller.tick();
+        }
+        assert_eq!(scaled_brightness_at(&scroller, 0, 0, 0, MAX_BRIGHTNESS), MAX_BRIGHTNESS);
+        assert_eq!(scaled_brightness_at(&scroller, 1, 0, 0, MAX_BRIGHTNESS), 0);
+    }
+
+    #[test]
+    fn scaled_brightness_at_blends_between_adjacent_columns() {
+        let mut scroller = TestScroller {
+            state: Default::default(),
+            glyph: BitImage::new([0b10000, 0, 0, 0, 0]),
+        };
+        // The animation starts blank; scroll the glyph fully onto the display.
+        for _ in 0..GLYPH_WIDTH {
+            scroller.tick();
+        }
+        let halfway = scaled_brightness_at(&scroller, 0, 0, SUB_COLUMNS / 2, MAX_BRIGHTNESS);
+        assert!(halfway > 0 && halfway < MAX_BRIGHTNESS);
+    }
+
+    #[test]
+    fn set_message_combines_non_rendering_codes_on_one_cell() {
+        let mut text = ScrollingBufferedText::default();
+        text.set_message(b"\x1bb3\x1bs2X");
+        assert_eq!(text.length, 1);
+        assert_eq!(text.message[0], b'X');
+        assert_eq!(text.events[0].brightness, Some(3));
+        assert_eq!(text.events[0].speed, Some(2));
+        assert_eq!(text.events[0].icon, None);
+    }
+
+    #[test]
+    fn set_message_combines_brightness_with_a_following_icon() {
+        let mut text = ScrollingBufferedText::default();
+        text.set_message(b"\x1bb3\x1bi0");
+        assert_eq!(text.length, 1);
+        assert_eq!(text.message[0], b' ');
+        assert_eq!(text.events[0].brightness, Some(3));
+        assert_eq!(text.events[0].icon, Some(0));
+    }
+
+    #[test]
+    fn set_message_pause_inserts_n_blank_cells() {
+        let mut text = ScrollingBufferedText::default();
+        text.set_message(b"\x1bp3A");
+        assert_eq!(text.length, 4);
+        assert_eq!(&text.message[..4], b"   A");
+    }
+
+    #[test]
+    fn set_message_pause_saturates_instead_of_overrunning_capacity() {
+        // 125 real characters leave room for only 3 more cells; requesting a
+        // 9-cell pause must not overrun `push_cell`'s 128-cell capacity.
+        let mut message = [b'A'; 128];
+        message[125] = CONTROL_ESCAPE;
+        message[126] = b'p';
+        message[127] = b'9';
+        let mut text = ScrollingBufferedText::default();
+        text.set_message(&message);
+        assert_eq!(text.length, 128);
+    }
+
+    #[test]
+    fn stream_push_rotates_tail_once_full() {
+        let mut stream = ScrollingStreamText::default();
+        for byte in 0..STREAM_CAPACITY as u8 {
+            stream.push(byte);
+        }
+        assert_eq!(stream.length, STREAM_CAPACITY);
+        assert_eq!(stream.tail, 0);
+        stream.push(b'!');
+        assert_eq!(stream.length, STREAM_CAPACITY);
+        assert_eq!(stream.tail, 1);
+    }
+
+    #[test]
+    fn stream_push_eviction_does_not_skip_the_displayed_character() {
+        let mut stream = ScrollingStreamText::default();
+        for byte in 0..STREAM_CAPACITY as u8 {
+            stream.push(byte);
+        }
+        for _ in 0..GLYPH_WIDTH * 2 {
+            stream.tick();
+        }
+        let before = [
+            stream.brightness_at(0, 0),
+            stream.brightness_at(1, 0),
+            stream.brightness_at(2, 0),
+            stream.brightness_at(3, 0),
+        ];
+        stream.push(b'!');
+        let after = [
+            stream.brightness_at(0, 0),
+            stream.brightness_at(1, 0),
+            stream.brightness_at(2, 0),
+            stream.brightness_at(3, 0),
+        ];
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn static_text_progress_tracks_is_finished() {
+        let mut text = ScrollingStaticText::default();
+        text.set_message(b"Hi");
+        assert_eq!(text.progress(), (0, text.total_columns()));
+        while !text.is_finished() {
+            assert!(text.progress().0 < text.total_columns());
+            text.tick();
+        }
+        assert_eq!(text.progress(), (text.total_columns(), text.total_columns()));
+        assert_eq!(text.progress_fraction(), u8::MAX);
+    }
+
+    #[test]
+    fn buffered_text_progress_tracks_is_finished() {
+        let mut text = ScrollingBufferedText::default();
+        text.set_message(b"Hi");
+        assert_eq!(text.progress(), (0, text.total_columns()));
+        while !text.is_finished() {
+            assert!(text.progress().0 < text.total_columns());
+            text.tick();
+        }
+        assert_eq!(text.progress(), (text.total_columns(), text.total_columns()));
+        assert_eq!(text.progress_fraction(), u8::MAX);
+    }
+
+    #[test]
+    fn stream_text_progress_saturates_once_the_buffer_is_fully_scrolled_through() {
+        let mut stream = ScrollingStreamText::default();
+        stream.push_str(b"Hi");
+        let total = stream.total_columns();
+        for _ in 0..total {
+            assert!(!stream.is_finished());
+            stream.tick();
+        }
+        assert_eq!(stream.progress(), (total, total));
+        assert_eq!(stream.progress_fraction(), u8::MAX);
+        assert!(!stream.is_finished());
+    }
 }
 